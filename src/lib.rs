@@ -7,7 +7,7 @@
 //! # Examples
 //! ```
 //! use proc_macro2::TokenStream;
-//! use quasi::interpolate;
+//! use quasi::{interpolate, Replacement};
 //! use quote::ToTokens;
 //! use std::collections::HashMap;
 //! use syn::{Ident, parse_str};
@@ -15,9 +15,9 @@
 //! let input: TokenStream = parse_str("let NAME: int = 5;")?;
 //! let expected: TokenStream = parse_str("let age: int = 5;")?;
 //!
-//! let mut replacements: HashMap<&str, &dyn ToTokens> = HashMap::new();
+//! let mut replacements: HashMap<&str, Replacement> = HashMap::new();
 //! let ident = parse_str::<Ident>("age")?;
-//! replacements.insert("NAME", &ident);
+//! replacements.insert("NAME", Replacement::One(&ident));
 //!
 //! let output = interpolate(input, &replacements);
 //! assert_eq!(
@@ -38,7 +38,7 @@
 //! use proc_macro2::TokenStream;
 //! use std::collections::HashMap;
 //! use syn::{Ident, parse::{Parse, ParseStream, Result}, parse_macro_input, punctuated::Punctuated, Token};
-//! use quasi::{Interpolate, interpolate};
+//! use quasi::{Interpolate, interpolate, Replacement};
 //! use quote::ToTokens;
 //!
 //! /// Create a token for macro using [syn](syn)
@@ -67,13 +67,13 @@
 //! /// Make KeyValue interpolatible
 //! impl Interpolate for KeyValue {
 //!     fn interpolate(&self, stream: TokenStream) -> TokenStream {
-//!         let mut replacements: HashMap<_, &dyn ToTokens> = HashMap::new();
+//!         let mut replacements: HashMap<_, Replacement> = HashMap::new();
 //!
 //!         // Replace each "KEY" with the key
-//!         replacements.insert("KEY", &self.key);
+//!         replacements.insert("KEY", Replacement::One(&self.key));
 //!
 //!         // Replace each "VALUE" with the value
-//!         replacements.insert("VALUE", &self.value);
+//!         replacements.insert("VALUE", Replacement::One(&self.value));
 //!
 //!         interpolate(stream, &replacements)
 //!     }
@@ -107,9 +107,9 @@
 //! }
 //! ```
 
-use proc_macro2::{Group, TokenStream, TokenTree};
+use proc_macro2::{Delimiter, Group, Literal, Span, TokenStream, TokenTree};
 use quote::{ToTokens, TokenStreamExt};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use syn::punctuated::Punctuated;
 
 /// Trait for tokens that can replace interpolation markers
@@ -130,24 +130,204 @@ impl<T: Interpolate, P> Interpolate for Punctuated<T, P> {
     }
 }
 
+/// Builder that interpolates a [Punctuated] list with a configurable separator and/or terminator between
+/// interpolated fragments, mirroring [quote]'s `append_separated`/`append_terminated`. The plain [Interpolate] impl
+/// on [Punctuated] stays the no-separator default; reach for this when the fragments need to read as a list, e.g.
+/// a comma-separated argument list rather than standalone statements.
+pub struct InterpolateJoined<'a, T, P> {
+    items: &'a Punctuated<T, P>,
+    separator: TokenStream,
+    terminator: Option<TokenStream>,
+}
+
+impl<'a, T, P> InterpolateJoined<'a, T, P> {
+    /// Create a builder over `items` with no separator or terminator
+    pub fn new(items: &'a Punctuated<T, P>) -> Self {
+        InterpolateJoined {
+            items,
+            separator: TokenStream::new(),
+            terminator: None,
+        }
+    }
+
+    /// Emit `separator` between each interpolated fragment, but not after the last one
+    pub fn separated_by(mut self, separator: impl ToTokens) -> Self {
+        self.separator = separator.into_token_stream();
+        self
+    }
+
+    /// Emit `terminator` after every interpolated fragment, including the last one
+    pub fn terminated_by(mut self, terminator: impl ToTokens) -> Self {
+        self.terminator = Some(terminator.into_token_stream());
+        self
+    }
+}
+
+/// Make an [InterpolateJoined] builder interpolatible, joining its fragments with the configured separator and
+/// terminator
+impl<T: Interpolate, P> Interpolate for InterpolateJoined<'_, T, P> {
+    fn interpolate(&self, stream: TokenStream) -> TokenStream {
+        self.items
+            .iter()
+            .enumerate()
+            .fold(TokenStream::new(), |mut new, (index, item)| {
+                if index > 0 {
+                    new.extend(self.separator.clone());
+                }
+
+                new.extend(item.interpolate(stream.clone()));
+
+                if let Some(terminator) = &self.terminator {
+                    new.extend(terminator.clone());
+                }
+
+                new
+            })
+    }
+}
+
+/// A value an interpolation marker can be replaced with.
+///
+/// [One](Replacement::One) is a plain, single substitution as before. [Many](Replacement::Many) drives the
+/// `EACH(key) { .. }` repetition marker: it holds one replacement map per iteration, which is overlaid on top of the
+/// outer map (so markers not mentioned in the iteration still resolve against the enclosing scope) while the
+/// marked-up group is interpolated once per entry.
+#[derive(Clone)]
+pub enum Replacement<'a> {
+    /// Replace the marker with this single value's token rendering
+    One(&'a dyn ToTokens),
+    /// Expand the following group once per entry, overlaying each entry's map on the outer replacements
+    Many(Vec<HashMap<&'a str, Replacement<'a>>>),
+}
+
 /// Replace the interpolation markers in a token stream with a specific text.
 /// See this [crate's](crate) documentation for an example on how to use this.
 pub fn interpolate(
     stream: TokenStream,
-    replacements: &HashMap<&str, &dyn ToTokens>,
+    replacements: &HashMap<&str, Replacement>,
+) -> TokenStream {
+    interpolate_inner(stream, replacements, None, None)
+}
+
+/// Like [interpolate], but in a strict mode that reports unknown markers and unused replacements instead of
+/// silently ignoring them. A marker is any all-uppercase identifier (e.g. `NAME`, `TRAIT`) or `{MARKER}` string
+/// placeholder; lowercase code is never flagged even when it has no matching replacement.
+///
+/// Unused-replacement checking recurses into every per-iteration map of an `EACH`'s `Many` replacement: a key
+/// belonging to one iteration's own map is judged by whether that iteration's body used it, never by whether an
+/// outer key of the same name happened to be used elsewhere.
+pub fn try_interpolate(
+    stream: TokenStream,
+    replacements: &HashMap<&str, Replacement>,
+) -> Result<TokenStream, InterpolateError> {
+    let mut tracker = Tracker::default();
+    let mut consumed = HashSet::new();
+    let output = interpolate_inner(stream, replacements, Some(&mut tracker), Some(&mut consumed));
+
+    let mut errors = tracker.errors;
+    errors.extend(tracker.unknown.into_iter().map(|(marker, span)| {
+        syn::Error::new(span, format!("unknown interpolation marker `{marker}`"))
+    }));
+    errors.extend(replacements.keys().filter(|key| !consumed.contains(**key)).map(|key| {
+        syn::Error::new(
+            Span::call_site(),
+            format!("replacement `{key}` was never used by the template"),
+        )
+    }));
+
+    if errors.is_empty() {
+        Ok(output)
+    } else {
+        Err(InterpolateError { errors })
+    }
+}
+
+/// One or more problems found by [try_interpolate]: unknown markers in the template, replacements that were never
+/// consumed, or both.
+#[derive(Debug)]
+pub struct InterpolateError {
+    errors: Vec<syn::Error>,
+}
+
+impl InterpolateError {
+    /// Render all collected problems as a `TokenStream` of `compile_error!` invocations, one per problem, each
+    /// pointing at the offending span where one is known.
+    pub fn to_compile_error(&self) -> TokenStream {
+        self.errors
+            .iter()
+            .fold(TokenStream::new(), |mut stream, error| {
+                stream.extend(error.to_compile_error());
+                stream
+            })
+    }
+}
+
+impl std::fmt::Display for InterpolateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for error in &self.errors {
+            writeln!(f, "{error}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for InterpolateError {}
+
+/// Accumulates, across a whole interpolation walk, every marker-shaped identifier that had no matching replacement
+/// and every "replacement was never used" error found along the way (including inside nested `EACH` iteration
+/// maps, reported as soon as that iteration's body finishes).
+#[derive(Default)]
+struct Tracker {
+    unknown: Vec<(String, Span)>,
+    errors: Vec<syn::Error>,
+}
+
+/// Reborrow an `Option<&mut T>` so it can be passed into a nested call while the original stays usable afterwards.
+fn reborrow<'t, T>(value: &'t mut Option<&mut T>) -> Option<&'t mut T> {
+    match value {
+        Some(value) => Some(&mut **value),
+        None => None,
+    }
+}
+
+/// An all-uppercase identifier is treated as a marker name under the strict-mode naming convention; ordinary
+/// lowercase or mixed-case code is never flagged as unknown.
+fn looks_like_marker(ident_str: &str) -> bool {
+    ident_str.chars().any(char::is_alphabetic) && !ident_str.chars().any(char::is_lowercase)
+}
+
+fn interpolate_inner(
+    stream: TokenStream,
+    replacements: &HashMap<&str, Replacement>,
+    mut tracker: Option<&mut Tracker>,
+    mut consumed: Option<&mut HashSet<String>>,
 ) -> TokenStream {
     let mut new = TokenStream::new();
+    let mut tokens = stream.into_iter().peekable();
 
     // Loop over each token in the stream
-    // `Literal`, `Punct`, and `Group` are kept as is
-    for token in stream.into_iter() {
+    // `Literal` and `Punct` are kept as is
+    while let Some(token) = tokens.next() {
         match token {
-            TokenTree::Literal(literal) => new.append(literal),
+            TokenTree::Literal(literal) => new.append(interpolate_literal(
+                literal,
+                replacements,
+                reborrow(&mut tracker),
+                reborrow(&mut consumed),
+            )),
             TokenTree::Punct(punct) => new.append(punct),
             TokenTree::Group(group) => {
                 // Recursively interpolate the stream in group
-                let mut new_group =
-                    Group::new(group.delimiter(), interpolate(group.stream(), replacements));
+                let mut new_group = Group::new(
+                    group.delimiter(),
+                    interpolate_inner(
+                        group.stream(),
+                        replacements,
+                        reborrow(&mut tracker),
+                        reborrow(&mut consumed),
+                    ),
+                );
                 new_group.set_span(group.span());
 
                 new.append(new_group);
@@ -155,14 +335,178 @@ pub fn interpolate(
             TokenTree::Ident(ident) => {
                 let ident_str: &str = &ident.to_string();
 
+                // `EACH(key) { .. }` / `EACH(key) sep(..) { .. }` repeats the group once per entry of the `Many`
+                // replacement named by `key`. Only commit to this reading once a parenthesized group actually
+                // follows; otherwise `EACH` is just an ordinary identifier.
+                if ident_str == "EACH" {
+                    if let Some(TokenTree::Group(key_group)) = tokens.peek() {
+                        if key_group.delimiter() == Delimiter::Parenthesis {
+                            let key_group = match tokens.next() {
+                                Some(TokenTree::Group(group)) => group,
+                                _ => unreachable!(),
+                            };
+                            let key = key_group.stream().to_string();
+
+                            if let Some(Replacement::Many(entries)) =
+                                replacements.get(key.as_str())
+                            {
+                                if let Some(c) = reborrow(&mut consumed) {
+                                    c.insert(key.clone());
+                                }
+
+                                let separator = if let Some(TokenTree::Ident(sep_ident)) =
+                                    tokens.peek()
+                                {
+                                    if *sep_ident == "sep" {
+                                        tokens.next();
+                                        match tokens.next() {
+                                            Some(TokenTree::Group(sep_group)) => {
+                                                sep_group.stream()
+                                            }
+                                            _ => TokenStream::new(),
+                                        }
+                                    } else {
+                                        TokenStream::new()
+                                    }
+                                } else {
+                                    TokenStream::new()
+                                };
+
+                                let body = match tokens.next() {
+                                    Some(TokenTree::Group(body_group)) => body_group.stream(),
+                                    _ => TokenStream::new(),
+                                };
+
+                                for (index, entry) in entries.iter().enumerate() {
+                                    if index > 0 {
+                                        new.extend(separator.clone());
+                                    }
+
+                                    let mut overlaid = replacements.clone();
+                                    overlaid.extend(
+                                        entry.iter().map(|(key, value)| (*key, value.clone())),
+                                    );
+
+                                    // Each iteration gets its own consumption scope: a key belonging to this
+                                    // entry's own map must be judged by whether this entry's body used it, not by
+                                    // whether some other scope used a same-named key. Only bother tracking at all
+                                    // when something downstream actually reads it.
+                                    let mut entry_consumed =
+                                        (tracker.is_some() || consumed.is_some())
+                                            .then(HashSet::new);
+                                    new.extend(interpolate_inner(
+                                        body.clone(),
+                                        &overlaid,
+                                        reborrow(&mut tracker),
+                                        entry_consumed.as_mut(),
+                                    ));
+
+                                    if let Some(entry_consumed) = &entry_consumed {
+                                        if let Some(t) = reborrow(&mut tracker) {
+                                            for entry_key in entry.keys() {
+                                                if !entry_consumed.contains(*entry_key) {
+                                                    t.errors.push(syn::Error::new(
+                                                        Span::call_site(),
+                                                        format!(
+                                                            "replacement `{entry_key}` was never used by the template"
+                                                        ),
+                                                    ));
+                                                }
+                                            }
+                                        }
+
+                                        // Bubble up only usage of keys that belong to the outer scope (i.e. this
+                                        // entry didn't shadow them) - an entry-owned key being used must never mark
+                                        // an identically named outer key as consumed.
+                                        if let Some(c) = reborrow(&mut consumed) {
+                                            for used_key in entry_consumed {
+                                                if !entry.contains_key(used_key.as_str()) {
+                                                    c.insert(used_key.clone());
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+
+                                continue;
+                            }
+
+                            if let Some(t) = reborrow(&mut tracker) {
+                                t.unknown.push((format!("EACH({key})"), key_group.span()));
+                            }
+
+                            // Key missing from the replacements: leave the marker untouched, as with any other
+                            // unmatched identifier.
+                            new.append(TokenTree::Ident(ident));
+                            new.append(key_group);
+                            continue;
+                        }
+                    }
+                }
+
                 // Check if identifier is in the replacement set
-                if let Some(value) = replacements.get(ident_str) {
+                if let Some(Replacement::One(value)) = replacements.get(ident_str) {
+                    if let Some(c) = reborrow(&mut consumed) {
+                        c.insert(ident_str.to_string());
+                    }
+
                     // Replace with replacement value
                     value.to_tokens(&mut new);
 
                     continue;
                 }
 
+                // No whole-identifier match; try markers embedded inside a larger identifier, e.g. `get__NAME__`.
+                if let Some(spliced) =
+                    splice_ident_markers(ident_str, replacements, reborrow(&mut consumed))
+                {
+                    if let Ok(new_ident) = syn::parse_str::<proc_macro2::Ident>(&spliced) {
+                        new.append(proc_macro2::Ident::new(&new_ident.to_string(), ident.span()));
+
+                        continue;
+                    }
+                }
+
+                // A `:case` suffix (e.g. `__NAME:pascal__Builder`) tokenizes as this ident, a separate `Punct(':')`,
+                // and a following ident, since `:` cannot appear inside a single identifier token. Peek across
+                // that boundary and retry the splice against the reassembled text.
+                if ident_str.contains("__") {
+                    let mut lookahead = tokens.clone();
+
+                    if let (Some(TokenTree::Punct(colon)), Some(TokenTree::Ident(case_ident))) =
+                        (lookahead.next(), lookahead.next())
+                    {
+                        if colon.as_char() == ':' {
+                            let combined = format!("{ident_str}:{case_ident}");
+
+                            if let Some(spliced) = splice_ident_markers(
+                                &combined,
+                                replacements,
+                                reborrow(&mut consumed),
+                            ) {
+                                if let Ok(new_ident) =
+                                    syn::parse_str::<proc_macro2::Ident>(&spliced)
+                                {
+                                    tokens.next();
+                                    tokens.next();
+                                    new.append(proc_macro2::Ident::new(
+                                        &new_ident.to_string(),
+                                        ident.span(),
+                                    ));
+
+                                    continue;
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if looks_like_marker(ident_str) && !replacements.contains_key(ident_str) {
+                    if let Some(t) = reborrow(&mut tracker) {
+                        t.unknown.push((ident_str.to_string(), ident.span()));
+                    }
+                }
+
                 // Identifier did not match, so copy as is
                 new.append(ident);
             }
@@ -172,6 +516,180 @@ pub fn interpolate(
     new
 }
 
+/// Replace `{MARKER}` placeholders inside a string literal with their rendered replacement text. Non-string
+/// literals are returned unchanged; `{{` and `}}` escape literal braces.
+fn interpolate_literal(
+    literal: Literal,
+    replacements: &HashMap<&str, Replacement>,
+    tracker: Option<&mut Tracker>,
+    consumed: Option<&mut HashSet<String>>,
+) -> Literal {
+    let stream = TokenStream::from(TokenTree::Literal(literal.clone()));
+
+    let Ok(syn::Lit::Str(lit_str)) = syn::parse2::<syn::Lit>(stream) else {
+        return literal;
+    };
+
+    let rendered = interpolate_str_literal(
+        &lit_str.value(),
+        replacements,
+        literal.span(),
+        tracker,
+        consumed,
+    );
+    let mut new_literal = Literal::string(&rendered);
+    new_literal.set_span(literal.span());
+
+    new_literal
+}
+
+/// Scan a string literal's contents for `{MARKER}` placeholders, replacing each with the rendered token text of the
+/// matching key's replacement. `{{`/`}}` escape literal braces; an unmatched or unterminated placeholder is left as
+/// written. Only brace contents that look like a marker name (non-empty, all-uppercase, per
+/// [looks_like_marker](looks_like_marker)) are ever treated as one, so ordinary `format!`/`write!` placeholders
+/// like `{}`, `{:?}`, `{0}` or `{:width$}` pass through untouched and are never flagged as unknown markers.
+fn interpolate_str_literal(
+    text: &str,
+    replacements: &HashMap<&str, Replacement>,
+    span: Span,
+    mut tracker: Option<&mut Tracker>,
+    mut consumed: Option<&mut HashSet<String>>,
+) -> String {
+    let mut result = String::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                result.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                result.push('}');
+            }
+            '{' => {
+                let mut marker = String::new();
+                let mut closed = false;
+
+                for next in chars.by_ref() {
+                    if next == '}' {
+                        closed = true;
+                        break;
+                    }
+                    marker.push(next);
+                }
+
+                let is_marker = closed && looks_like_marker(&marker);
+
+                if is_marker {
+                    if let Some(Replacement::One(value)) = replacements.get(marker.as_str()) {
+                        if let Some(c) = reborrow(&mut consumed) {
+                            c.insert(marker.clone());
+                        }
+
+                        result.push_str(&value.to_token_stream().to_string());
+                        continue;
+                    }
+
+                    if let Some(t) = reborrow(&mut tracker) {
+                        t.unknown.push((marker.clone(), span));
+                    }
+                }
+
+                // Not a marker-shaped placeholder (e.g. a `format!`-style `{}`, `{:?}`, `{0}`), or an unmatched or
+                // unterminated one: leave the original text untouched
+                result.push('{');
+                result.push_str(&marker);
+                if closed {
+                    result.push('}');
+                }
+            }
+            _ => result.push(c),
+        }
+    }
+
+    result
+}
+
+/// Replace every `__KEY__` / `__KEY:case__` marker embedded inside `ident_str` with the rendered text of `KEY`'s
+/// replacement, returning `None` if no such marker was found. A marker whose key is absent from `replacements` is
+/// left untouched rather than spliced.
+fn splice_ident_markers(
+    ident_str: &str,
+    replacements: &HashMap<&str, Replacement>,
+    mut consumed: Option<&mut HashSet<String>>,
+) -> Option<String> {
+    let mut result = String::new();
+    let mut changed = false;
+    let mut rest = ident_str;
+
+    while let Some(start) = rest.find("__") {
+        let (before, after_start) = rest.split_at(start);
+        let after_start = &after_start[2..];
+
+        match after_start.find("__") {
+            Some(end) if !after_start[..end].is_empty() => {
+                let marker = &after_start[..end];
+                let (key, case) = match marker.split_once(':') {
+                    Some((key, case)) => (key, Some(case)),
+                    None => (marker, None),
+                };
+
+                if let Some(Replacement::One(value)) = replacements.get(key) {
+                    if let Some(c) = reborrow(&mut consumed) {
+                        c.insert(key.to_string());
+                    }
+
+                    result.push_str(before);
+                    result.push_str(&apply_case(&identifier_portion(value), case));
+
+                    changed = true;
+                    rest = &after_start[end + 2..];
+                    continue;
+                }
+            }
+            _ => {}
+        }
+
+        // Not a recognized marker: copy the literal `__` and keep scanning after it.
+        result.push_str(before);
+        result.push_str("__");
+        rest = after_start;
+    }
+
+    result.push_str(rest);
+
+    if changed {
+        Some(result)
+    } else {
+        None
+    }
+}
+
+/// Render a replacement value and take its leading identifier-like portion, e.g. `Vec<T>` becomes `Vec`.
+fn identifier_portion(value: &dyn ToTokens) -> String {
+    value
+        .to_token_stream()
+        .to_string()
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect()
+}
+
+/// Apply an optional heck case transform, as named in an `__KEY:case__` marker
+fn apply_case(fragment: &str, case: Option<&str>) -> String {
+    use heck::{ToLowerCamelCase, ToPascalCase, ToShoutySnakeCase, ToSnakeCase};
+
+    match case {
+        Some("pascal") => fragment.to_pascal_case(),
+        Some("snake") => fragment.to_snake_case(),
+        Some("screaming") => fragment.to_shouty_snake_case(),
+        Some("camel") => fragment.to_lower_camel_case(),
+        _ => fragment.to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -199,14 +717,14 @@ mod tests {
             }
         };
 
-        let mut r: HashMap<&str, &dyn ToTokens> = HashMap::new();
+        let mut r: HashMap<&str, Replacement> = HashMap::new();
         let v: Ident = parse_str("var")?;
         let a: Type = parse_str("abstract_type")?;
         let c: Type = parse_str("concrete")?;
 
-        r.insert("VAR", &v);
-        r.insert("TRAIT", &a);
-        r.insert("CONCRETE", &c);
+        r.insert("VAR", Replacement::One(&v));
+        r.insert("TRAIT", Replacement::One(&a));
+        r.insert("CONCRETE", Replacement::One(&c));
 
         assert_eq!(
             format!("{}", &interpolate(input, &r)),
@@ -222,9 +740,9 @@ mod tests {
         let input: TokenStream = parse_str("let a: TRAIT = OTHER;")?;
         let expected: TokenStream = parse_str("let a: Display = OTHER;")?;
 
-        let mut r: HashMap<&str, &dyn ToTokens> = HashMap::new();
+        let mut r: HashMap<&str, Replacement> = HashMap::new();
         let t: Type = parse_str("Display")?;
-        r.insert("TRAIT", &t);
+        r.insert("TRAIT", Replacement::One(&t));
 
         assert_eq!(
             format!("{}", interpolate(input, &r)),
@@ -246,13 +764,13 @@ mod tests {
         /// Make TraitSpecifier interpolatible
         impl Interpolate for TraitSpecifier {
             fn interpolate(&self, stream: TokenStream) -> TokenStream {
-                let mut replacements: HashMap<_, &dyn ToTokens> = HashMap::new();
+                let mut replacements: HashMap<_, Replacement> = HashMap::new();
 
                 // Replace each "TRAIT" with the absract trait
-                replacements.insert("TRAIT", &self.abstract_trait);
+                replacements.insert("TRAIT", Replacement::One(&self.abstract_trait));
 
                 // Replace each "CONCRETE" with the concrete type
-                replacements.insert("CONCRETE", &self.concrete);
+                replacements.insert("CONCRETE", Replacement::One(&self.concrete));
 
                 interpolate(stream, &replacements)
             }
@@ -285,4 +803,434 @@ mod tests {
 
         Ok(())
     }
+
+    /// `EACH` should expand the group once per entry, overlaying each entry's map on the outer one
+    #[test]
+    fn each_expands_once_per_entry() -> Result {
+        let input: TokenStream = parse_str("STRUCT { EACH(fields) sep(,) { NAME: TYPE } }")?;
+        let expected: TokenStream = parse_str("Point { x: i32, y: i32 }")?;
+
+        let s: Ident = parse_str("Point")?;
+        let x_name: Ident = parse_str("x")?;
+        let x_type: Type = parse_str("i32")?;
+        let y_name: Ident = parse_str("y")?;
+        let y_type: Type = parse_str("i32")?;
+
+        let mut x_entry: HashMap<&str, Replacement> = HashMap::new();
+        x_entry.insert("NAME", Replacement::One(&x_name));
+        x_entry.insert("TYPE", Replacement::One(&x_type));
+
+        let mut y_entry: HashMap<&str, Replacement> = HashMap::new();
+        y_entry.insert("NAME", Replacement::One(&y_name));
+        y_entry.insert("TYPE", Replacement::One(&y_type));
+
+        let mut r: HashMap<&str, Replacement> = HashMap::new();
+        r.insert("STRUCT", Replacement::One(&s));
+        r.insert("fields", Replacement::Many(vec![x_entry, y_entry]));
+
+        assert_eq!(
+            format!("{}", interpolate(input, &r)),
+            format!("{}", expected)
+        );
+
+        Ok(())
+    }
+
+    /// An empty `Many` list should produce no tokens for the `EACH` group
+    #[test]
+    fn each_with_empty_list_produces_nothing() -> Result {
+        let input: TokenStream = parse_str("struct STRUCT { EACH(fields) sep(,) { NAME: TYPE } }")?;
+        let expected: TokenStream = parse_str("struct Empty { }")?;
+
+        let s: Ident = parse_str("Empty")?;
+
+        let mut r: HashMap<&str, Replacement> = HashMap::new();
+        r.insert("STRUCT", Replacement::One(&s));
+        r.insert("fields", Replacement::Many(Vec::new()));
+
+        assert_eq!(
+            format!("{}", interpolate(input, &r)),
+            format!("{}", expected)
+        );
+
+        Ok(())
+    }
+
+    /// A missing `Many` key should leave the `EACH(key)` marker untouched
+    #[test]
+    fn each_with_missing_key_is_untouched() -> Result {
+        let input: TokenStream = parse_str("EACH(missing) { NAME }")?;
+        let expected: TokenStream = parse_str("EACH(missing) { NAME }")?;
+
+        let r: HashMap<&str, Replacement> = HashMap::new();
+
+        assert_eq!(
+            format!("{}", interpolate(input, &r)),
+            format!("{}", expected)
+        );
+
+        Ok(())
+    }
+
+    /// Nested `EACH` markers resolve against the innermost overlaid map first
+    #[test]
+    fn nested_each_resolves_innermost_first() -> Result {
+        let input: TokenStream =
+            parse_str("EACH(outer) sep(,) { NAME { EACH(inner) sep(,) { NAME } } }")?;
+        let expected: TokenStream = parse_str("a { one, two }, b { three }")?;
+
+        let a: Ident = parse_str("a")?;
+        let b: Ident = parse_str("b")?;
+        let one: Ident = parse_str("one")?;
+        let two: Ident = parse_str("two")?;
+        let three: Ident = parse_str("three")?;
+
+        let mut one_entry: HashMap<&str, Replacement> = HashMap::new();
+        one_entry.insert("NAME", Replacement::One(&one));
+        let mut two_entry: HashMap<&str, Replacement> = HashMap::new();
+        two_entry.insert("NAME", Replacement::One(&two));
+        let mut three_entry: HashMap<&str, Replacement> = HashMap::new();
+        three_entry.insert("NAME", Replacement::One(&three));
+
+        let mut a_entry: HashMap<&str, Replacement> = HashMap::new();
+        a_entry.insert("NAME", Replacement::One(&a));
+        a_entry.insert("inner", Replacement::Many(vec![one_entry, two_entry]));
+
+        let mut b_entry: HashMap<&str, Replacement> = HashMap::new();
+        b_entry.insert("NAME", Replacement::One(&b));
+        b_entry.insert("inner", Replacement::Many(vec![three_entry]));
+
+        let mut r: HashMap<&str, Replacement> = HashMap::new();
+        r.insert("outer", Replacement::Many(vec![a_entry, b_entry]));
+
+        assert_eq!(
+            format!("{}", interpolate(input, &r)),
+            format!("{}", expected)
+        );
+
+        Ok(())
+    }
+
+    /// A marker embedded inside a larger identifier should be spliced in, preserving the surrounding text
+    #[test]
+    fn marker_embedded_in_identifier() -> Result {
+        let input: TokenStream = parse_str("fn get__NAME__() {}")?;
+        let expected: TokenStream = parse_str("fn getage() {}")?;
+
+        let name: Ident = parse_str("age")?;
+        let mut r: HashMap<&str, Replacement> = HashMap::new();
+        r.insert("NAME", Replacement::One(&name));
+
+        assert_eq!(
+            format!("{}", interpolate(input, &r)),
+            format!("{}", expected)
+        );
+
+        Ok(())
+    }
+
+    /// A `:case` suffix on an embedded marker applies the matching heck conversion to the spliced fragment
+    #[test]
+    fn marker_embedded_with_case_conversion() -> Result {
+        let input: TokenStream = parse_str("struct __NAME:pascal__Builder;")?;
+        let expected: TokenStream = parse_str("struct BigWindowBuilder;")?;
+
+        let name: Ident = parse_str("big_window")?;
+        let mut r: HashMap<&str, Replacement> = HashMap::new();
+        r.insert("NAME", Replacement::One(&name));
+
+        assert_eq!(
+            format!("{}", interpolate(input, &r)),
+            format!("{}", expected)
+        );
+
+        Ok(())
+    }
+
+    /// A spliced identifier that would be invalid (starts with a digit) falls back to the original ident untouched
+    #[test]
+    fn marker_splice_falls_back_when_invalid() -> Result {
+        let input: TokenStream = parse_str("__NAME__")?;
+        let expected: TokenStream = parse_str("__NAME__")?;
+
+        // A literal renders as a bare digit, so splicing it in would produce an identifier starting with a digit.
+        let digit: syn::LitInt = parse_str("7")?;
+        let mut r: HashMap<&str, Replacement> = HashMap::new();
+        r.insert("NAME", Replacement::One(&digit));
+
+        assert_eq!(
+            format!("{}", interpolate(input, &r)),
+            format!("{}", expected)
+        );
+
+        Ok(())
+    }
+
+    /// `{MARKER}` placeholders inside string literals are replaced with the rendered replacement text
+    #[test]
+    fn marker_inside_string_literal() -> Result {
+        let input: TokenStream = parse_str(r#"compile_error!("missing {NAME}")"#)?;
+        let expected: TokenStream = parse_str(r#"compile_error!("missing name")"#)?;
+
+        let name: Ident = parse_str("name")?;
+        let mut r: HashMap<&str, Replacement> = HashMap::new();
+        r.insert("NAME", Replacement::One(&name));
+
+        assert_eq!(
+            format!("{}", interpolate(input, &r)),
+            format!("{}", expected)
+        );
+
+        Ok(())
+    }
+
+    /// `{{`/`}}` escape literal braces, and an unmatched placeholder is left untouched
+    #[test]
+    fn string_literal_escapes_and_unmatched_placeholder() -> Result {
+        let input: TokenStream = parse_str(r#""{{literal}} {MISSING}""#)?;
+        let expected: TokenStream = parse_str(r#""{literal} {MISSING}""#)?;
+
+        let r: HashMap<&str, Replacement> = HashMap::new();
+
+        assert_eq!(
+            format!("{}", interpolate(input, &r)),
+            format!("{}", expected)
+        );
+
+        Ok(())
+    }
+
+    /// Ordinary `format!`/`write!`-style placeholders aren't marker-shaped, so they pass through untouched even
+    /// though their brace contents never match anything in the replacement map
+    #[test]
+    fn string_literal_format_placeholders_are_untouched() -> Result {
+        let input: TokenStream =
+            parse_str(r#"compile_error!("error: {NAME} at {}, {:?}, {0}, {:width$}")"#)?;
+        let expected: TokenStream =
+            parse_str(r#"compile_error!("error: name at {}, {:?}, {0}, {:width$}")"#)?;
+
+        let name: Ident = parse_str("name")?;
+        let mut r: HashMap<&str, Replacement> = HashMap::new();
+        r.insert("NAME", Replacement::One(&name));
+
+        assert_eq!(
+            format!("{}", interpolate(input, &r)),
+            format!("{}", expected)
+        );
+
+        Ok(())
+    }
+
+    /// `try_interpolate` never flags `format!`/`write!`-style placeholders as unknown markers
+    #[test]
+    fn try_interpolate_ignores_format_placeholders() -> Result {
+        let input: TokenStream = parse_str(r#""{} {:?} {0}""#)?;
+        let expected: TokenStream = parse_str(r#""{} {:?} {0}""#)?;
+
+        let r: HashMap<&str, Replacement> = HashMap::new();
+
+        let output = try_interpolate(input, &r)?;
+        assert_eq!(format!("{}", output), format!("{}", expected));
+
+        Ok(())
+    }
+
+    /// Non-string literals are copied verbatim
+    #[test]
+    fn non_string_literal_is_untouched() -> Result {
+        let input: TokenStream = parse_str("42")?;
+        let expected: TokenStream = parse_str("42")?;
+
+        let r: HashMap<&str, Replacement> = HashMap::new();
+
+        assert_eq!(
+            format!("{}", interpolate(input, &r)),
+            format!("{}", expected)
+        );
+
+        Ok(())
+    }
+
+    /// `try_interpolate` succeeds once every marker has a replacement and every replacement is used
+    #[test]
+    fn try_interpolate_succeeds_when_balanced() -> Result {
+        let input: TokenStream = parse_str("let NAME: TYPE = 5;")?;
+        let expected: TokenStream = parse_str("let age: u8 = 5;")?;
+
+        let name: Ident = parse_str("age")?;
+        let ty: Type = parse_str("u8")?;
+        let mut r: HashMap<&str, Replacement> = HashMap::new();
+        r.insert("NAME", Replacement::One(&name));
+        r.insert("TYPE", Replacement::One(&ty));
+
+        let output = try_interpolate(input, &r)?;
+        assert_eq!(format!("{}", output), format!("{}", expected));
+
+        Ok(())
+    }
+
+    /// `try_interpolate` reports an all-uppercase marker that has no matching replacement
+    #[test]
+    fn try_interpolate_reports_unknown_marker() -> Result {
+        let input: TokenStream = parse_str("let NAME: TYPE = 5;")?;
+
+        let ty: Type = parse_str("u8")?;
+        let mut r: HashMap<&str, Replacement> = HashMap::new();
+        r.insert("TYPE", Replacement::One(&ty));
+
+        let err = try_interpolate(input, &r).unwrap_err();
+        assert!(err.to_string().contains("NAME"));
+
+        Ok(())
+    }
+
+    /// `try_interpolate` reports a replacement that the template never consumed
+    #[test]
+    fn try_interpolate_reports_unused_replacement() -> Result {
+        let input: TokenStream = parse_str("let NAME: u8 = 5;")?;
+
+        let name: Ident = parse_str("age")?;
+        let unused: Ident = parse_str("unused")?;
+        let mut r: HashMap<&str, Replacement> = HashMap::new();
+        r.insert("NAME", Replacement::One(&name));
+        r.insert("UNUSED", Replacement::One(&unused));
+
+        let err = try_interpolate(input, &r).unwrap_err();
+        assert!(err.to_string().contains("UNUSED"));
+
+        Ok(())
+    }
+
+    /// `try_interpolate` succeeds when every replacement, including those nested inside an
+    /// `EACH`'s per-entry maps, is consumed somewhere in the template
+    #[test]
+    fn try_interpolate_succeeds_with_each() -> Result {
+        let input: TokenStream = parse_str("STRUCT { EACH(fields) sep(,) { NAME: TYPE } }")?;
+
+        let s: Ident = parse_str("Point")?;
+        let x_name: Ident = parse_str("x")?;
+        let x_type: Type = parse_str("i32")?;
+
+        let mut entry: HashMap<&str, Replacement> = HashMap::new();
+        entry.insert("NAME", Replacement::One(&x_name));
+        entry.insert("TYPE", Replacement::One(&x_type));
+
+        let mut r: HashMap<&str, Replacement> = HashMap::new();
+        r.insert("STRUCT", Replacement::One(&s));
+        r.insert("fields", Replacement::Many(vec![entry]));
+
+        assert!(try_interpolate(input, &r).is_ok());
+
+        Ok(())
+    }
+
+    /// A key inside one `EACH` entry's own map that its iteration never consumes is reported,
+    /// even though a same-named key at the outer scope is used elsewhere in the template
+    #[test]
+    fn try_interpolate_reports_unused_replacement_inside_each_entry() -> Result {
+        let input: TokenStream = parse_str("NAME { EACH(fields) sep(,) { TYPE } }")?;
+
+        let outer_name: Ident = parse_str("Point")?;
+        let entry_name: Ident = parse_str("x")?;
+        let entry_type: Type = parse_str("i32")?;
+
+        let mut entry: HashMap<&str, Replacement> = HashMap::new();
+        entry.insert("NAME", Replacement::One(&entry_name));
+        entry.insert("TYPE", Replacement::One(&entry_type));
+
+        let mut r: HashMap<&str, Replacement> = HashMap::new();
+        r.insert("NAME", Replacement::One(&outer_name));
+        r.insert("fields", Replacement::Many(vec![entry]));
+
+        let err = try_interpolate(input, &r).unwrap_err();
+        assert!(err.to_string().contains("NAME"));
+
+        Ok(())
+    }
+
+    /// Consuming an `EACH` entry's own `NAME` key must not mark an identically named outer-scope
+    /// key as consumed if the outer template never actually used it
+    #[test]
+    fn try_interpolate_reports_unused_outer_replacement_not_masked_by_each_entry() -> Result {
+        let input: TokenStream = parse_str("STRUCT { EACH(fields) sep(,) { NAME: TYPE } }")?;
+
+        let s: Ident = parse_str("Point")?;
+        let unused_outer_name: Ident = parse_str("unused")?;
+        let x_name: Ident = parse_str("x")?;
+        let x_type: Type = parse_str("i32")?;
+
+        let mut entry: HashMap<&str, Replacement> = HashMap::new();
+        entry.insert("NAME", Replacement::One(&x_name));
+        entry.insert("TYPE", Replacement::One(&x_type));
+
+        let mut r: HashMap<&str, Replacement> = HashMap::new();
+        r.insert("STRUCT", Replacement::One(&s));
+        r.insert("NAME", Replacement::One(&unused_outer_name));
+        r.insert("fields", Replacement::Many(vec![entry]));
+
+        let err = try_interpolate(input, &r).unwrap_err();
+        assert!(err.to_string().contains("NAME"));
+
+        Ok(())
+    }
+
+    /// The infallible `interpolate` ignores the same unknown/unused conditions `try_interpolate` would report
+    #[test]
+    fn interpolate_ignores_errors() -> Result {
+        let input: TokenStream = parse_str("let NAME: u8 = 5;")?;
+        let expected: TokenStream = parse_str("let NAME: u8 = 5;")?;
+
+        let r: HashMap<&str, Replacement> = HashMap::new();
+
+        assert_eq!(
+            format!("{}", interpolate(input, &r)),
+            format!("{}", expected)
+        );
+
+        Ok(())
+    }
+
+    /// `InterpolateJoined` separates fragments with `separated_by` and terminates each with `terminated_by`
+    #[test]
+    fn interpolate_joined_separates_and_terminates() -> Result {
+        struct Arg {
+            name: Ident,
+            ty: Type,
+        }
+
+        impl Interpolate for Arg {
+            fn interpolate(&self, stream: TokenStream) -> TokenStream {
+                let mut replacements: HashMap<_, Replacement> = HashMap::new();
+
+                replacements.insert("NAME", Replacement::One(&self.name));
+                replacements.insert("TYPE", Replacement::One(&self.ty));
+
+                super::interpolate(stream, &replacements)
+            }
+        }
+
+        let mut args: Punctuated<Arg, Token![,]> = Punctuated::new();
+        args.push(Arg {
+            name: parse_str("a")?,
+            ty: parse_str("u8")?,
+        });
+        args.push(Arg {
+            name: parse_str("b")?,
+            ty: parse_str("bool")?,
+        });
+
+        let input = quote! { NAME: TYPE };
+        let expected = quote! { a: u8;, b: bool; };
+
+        let joined = InterpolateJoined::new(&args)
+            .separated_by(quote! { , })
+            .terminated_by(quote! { ; });
+
+        assert_eq!(
+            format!("{}", joined.interpolate(input)),
+            format!("{}", expected)
+        );
+
+        Ok(())
+    }
 }